@@ -1,11 +1,22 @@
 use crate::ffi::{
-    duckdb_destroy_value, duckdb_free, duckdb_get_bool, duckdb_get_double, duckdb_get_float, duckdb_get_int16,
-    duckdb_get_int32, duckdb_get_int64, duckdb_get_int8, duckdb_get_list_child, duckdb_get_list_size,
-    duckdb_get_type_id, duckdb_get_uint16, duckdb_get_uint32, duckdb_get_uint64, duckdb_get_uint8,
-    duckdb_get_value_type, duckdb_get_varchar, duckdb_is_null_value, duckdb_value,
+    duckdb_create_bool, duckdb_create_double, duckdb_create_float, duckdb_create_int16, duckdb_create_int32,
+    duckdb_create_int64, duckdb_create_int8, duckdb_create_list_value, duckdb_create_logical_type,
+    duckdb_create_null_value, duckdb_create_struct_type, duckdb_create_struct_value, duckdb_create_uint16,
+    duckdb_create_uint32, duckdb_create_uint64, duckdb_create_uint8, duckdb_create_varchar, duckdb_decimal_scale,
+    duckdb_destroy_logical_type, duckdb_destroy_value,
+    duckdb_free, duckdb_get_blob, duckdb_get_bool, duckdb_get_decimal, duckdb_get_double, duckdb_get_float, duckdb_get_hugeint,
+    duckdb_get_int16, duckdb_get_int32, duckdb_get_int64, duckdb_get_int8, duckdb_get_list_child, duckdb_get_list_size,
+    duckdb_get_map_key, duckdb_get_map_size, duckdb_get_map_value, duckdb_get_struct_child, duckdb_get_type_id,
+    duckdb_get_uhugeint, duckdb_get_uint16, duckdb_get_uint32, duckdb_get_uint64, duckdb_get_uint8,
+    duckdb_get_value_type, duckdb_get_varchar, duckdb_is_null_value, duckdb_struct_type_child_count,
+    duckdb_struct_type_child_name, duckdb_value,
 };
-use std::{ffi::CStr, fmt, os::raw::c_void};
 use crate::core::LogicalTypeId;
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    os::raw::c_void,
+};
 
 /// The Value object holds a single arbitrary value of any type that can be
 /// stored in the database.
@@ -41,6 +52,45 @@ impl Drop for Value {
     }
 }
 
+macro_rules! value_from_native {
+    ($($rust_type:ty => $ffi_func:ident),* $(,)?) => {
+        $(
+            impl From<$rust_type> for Value {
+                fn from(v: $rust_type) -> Self {
+                    Value::from(unsafe { $ffi_func(v) })
+                }
+            }
+        )*
+    };
+}
+
+value_from_native!(
+    bool => duckdb_create_bool,
+    i8 => duckdb_create_int8,
+    u8 => duckdb_create_uint8,
+    i16 => duckdb_create_int16,
+    u16 => duckdb_create_uint16,
+    i32 => duckdb_create_int32,
+    u32 => duckdb_create_uint32,
+    i64 => duckdb_create_int64,
+    u64 => duckdb_create_uint64,
+    f32 => duckdb_create_float,
+    f64 => duckdb_create_double,
+);
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        let c_str = CString::new(v).expect("value string must not contain interior NUL bytes");
+        Value::from(unsafe { duckdb_create_varchar(c_str.as_ptr()) })
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value::from(v.as_str())
+    }
+}
+
 impl Value {
     // Returns the value as a Rust type
     primitive_getters!(
@@ -68,16 +118,273 @@ impl Value {
         out
     }
 
+    /// Returns the value as a `Vec` of `(field name, field value)` pairs.
+    pub fn to_struct(&self) -> Vec<(String, Value)> {
+        unsafe {
+            let mut logical_type = duckdb_get_value_type(self.ptr);
+            let count = duckdb_struct_type_child_count(logical_type);
+            let mut out = Vec::with_capacity(count as usize);
+            for i in 0..count {
+                let name_ptr = duckdb_struct_type_child_name(logical_type, i);
+                let name = CStr::from_ptr(name_ptr).to_string_lossy().into_owned();
+                duckdb_free(name_ptr as *mut c_void);
+                let child = duckdb_get_struct_child(self.ptr, i);
+                out.push((name, Value::from(child)));
+            }
+            duckdb_destroy_logical_type(&mut logical_type);
+            out
+        }
+    }
+
+    /// Returns the value as a `Vec` of `(key, value)` pairs.
+    pub fn to_map(&self) -> Vec<(Value, Value)> {
+        let size = unsafe { duckdb_get_map_size(self.ptr) };
+        let mut out = Vec::with_capacity(size.try_into().unwrap());
+        for i in 0..size {
+            let key = unsafe { duckdb_get_map_key(self.ptr, i) };
+            let value = unsafe { duckdb_get_map_value(self.ptr, i) };
+            out.push((Value::from(key), Value::from(value)));
+        }
+        out
+    }
+
+    /// Reads a HUGEINT value, reconstructing the 128-bit integer from the
+    /// FFI's `lower`/`upper` hugeint representation.
+    pub fn to_i128(&self) -> Result<i128, FromValueError> {
+        self.require_type(LogicalTypeId::Hugeint)?;
+        let raw = unsafe { duckdb_get_hugeint(self.ptr) };
+        Ok(((raw.upper as i128) << 64) | raw.lower as i128)
+    }
+
+    /// Reads a UHUGEINT value, reconstructing the 128-bit integer from the
+    /// FFI's `lower`/`upper` uhugeint representation.
+    pub fn to_u128(&self) -> Result<u128, FromValueError> {
+        self.require_type(LogicalTypeId::Uhugeint)?;
+        let raw = unsafe { duckdb_get_uhugeint(self.ptr) };
+        Ok(((raw.upper as u128) << 64) | raw.lower as u128)
+    }
+
+    /// Reads a DECIMAL value as its unscaled integer plus scale, so callers
+    /// can reconstruct the exact value without going through lossy floats.
+    /// The unscaled integer is read through `duckdb_get_decimal` as a hugeint,
+    /// whatever the decimal's width.
+    pub fn to_decimal(&self) -> Result<(i128, u8), FromValueError> {
+        self.require_type(LogicalTypeId::Decimal)?;
+        unsafe {
+            let mut logical_type = duckdb_get_value_type(self.ptr);
+            let scale = duckdb_decimal_scale(logical_type);
+            duckdb_destroy_logical_type(&mut logical_type);
+            let raw = duckdb_get_decimal(self.ptr).value;
+            Ok((((raw.upper as i128) << 64) | raw.lower as i128, scale))
+        }
+    }
+
+    /// Returns the raw bytes backing a BLOB value.
+    pub fn to_blob(&self) -> Vec<u8> {
+        unsafe {
+            let blob = duckdb_get_blob(self.ptr);
+            let bytes = if blob.size == 0 {
+                Vec::new()
+            } else {
+                std::slice::from_raw_parts(blob.data as *const u8, blob.size as usize).to_vec()
+            };
+            duckdb_free(blob.data);
+            bytes
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         unsafe { duckdb_is_null_value(self.ptr) }
     }
 
     pub fn logical_type_id(&self) -> LogicalTypeId {
         unsafe {
-            let logical_type = duckdb_get_value_type(self.ptr);
-            duckdb_get_type_id(logical_type).into()
+            let mut logical_type = duckdb_get_value_type(self.ptr);
+            let id = duckdb_get_type_id(logical_type).into();
+            duckdb_destroy_logical_type(&mut logical_type);
+            id
         }
     }
+
+    /// Checks `logical_type_id()` against `expected`, so checked accessors can
+    /// share the same "wrong type" error path instead of hand-rolling it.
+    fn require_type(&self, expected: LogicalTypeId) -> Result<(), FromValueError> {
+        let found = self.logical_type_id();
+        if found != expected {
+            return Err(FromValueError::InvalidType { expected, found });
+        }
+        Ok(())
+    }
+
+    /// Returns a `Value` representing SQL `NULL`.
+    pub fn null() -> Self {
+        Value::from(unsafe { duckdb_create_null_value() })
+    }
+
+    /// Builds a LIST `Value` out of `values`, each of which must have logical
+    /// type `child_type`. The child values are consumed and destroyed once the
+    /// list value has been created.
+    ///
+    /// `child_type` only fixes the element type of an empty list: when `values`
+    /// is non-empty, the first child's own logical type is used instead, since
+    /// `duckdb_create_logical_type` can only build `child_type`'s bare type tag
+    /// and can't express a nested LIST/STRUCT child's real element/member type.
+    pub fn list(child_type: LogicalTypeId, values: impl IntoIterator<Item = Value>) -> Self {
+        let children: Vec<Value> = values.into_iter().collect();
+        let ptrs: Vec<duckdb_value> = children.iter().map(|v| v.ptr).collect();
+        let mut logical_type = match children.first() {
+            Some(first) => unsafe { duckdb_get_value_type(first.ptr) },
+            None => unsafe { duckdb_create_logical_type(child_type.into()) },
+        };
+        let duckdb_val = unsafe { duckdb_create_list_value(logical_type, ptrs.as_ptr().cast_mut(), ptrs.len() as u64) };
+        unsafe { duckdb_destroy_logical_type(&mut logical_type) };
+        Value::from(duckdb_val)
+    }
+
+    /// Builds a STRUCT `Value` out of `fields`, each member typed after its
+    /// own `Value`. Mirrors [`Value::list`]: the field values are consumed
+    /// and destroyed once the struct value has been created, and each member's
+    /// logical type is taken from the field's own value (not its bare
+    /// `LogicalTypeId` tag) so a nested LIST/STRUCT field keeps its real
+    /// element/member type.
+    pub fn from_struct(fields: impl IntoIterator<Item = (String, Value)>) -> Self {
+        let fields: Vec<(String, Value)> = fields.into_iter().collect();
+        let names: Vec<CString> = fields
+            .iter()
+            .map(|(name, _)| CString::new(name.as_str()).expect("struct field name must not contain interior NUL bytes"))
+            .collect();
+        let name_ptrs: Vec<_> = names.iter().map(|n| n.as_ptr()).collect();
+        let mut member_types: Vec<_> = fields.iter().map(|(_, v)| unsafe { duckdb_get_value_type(v.ptr) }).collect();
+        let mut member_values: Vec<duckdb_value> = fields.iter().map(|(_, v)| v.ptr).collect();
+        let mut struct_type = unsafe {
+            duckdb_create_struct_type(member_types.as_mut_ptr(), name_ptrs.as_ptr().cast_mut(), fields.len() as u64)
+        };
+        let duckdb_val = unsafe { duckdb_create_struct_value(struct_type, member_values.as_mut_ptr()) };
+        for t in &mut member_types {
+            unsafe { duckdb_destroy_logical_type(t) };
+        }
+        unsafe { duckdb_destroy_logical_type(&mut struct_type) };
+        Value::from(duckdb_val)
+    }
+
+    /// Fallibly extracts `T` from this value, checking `logical_type_id()` first
+    /// instead of trusting the caller to pick the right lossy getter.
+    pub fn get<T: FromDuckValue>(&self) -> Result<T, FromValueError> {
+        T::from_duck_value(self)
+    }
+
+    /// Reads the value through whichever signed/unsigned integer getter matches
+    /// `logical_type_id()` and widens the result to `i128`, so narrowing
+    /// `FromDuckValue` impls can `TryFrom` down to their target width.
+    ///
+    /// `expected` is the logical type the caller's target Rust type actually
+    /// maps to, so a mismatch reports the right type instead of always
+    /// blaming `Bigint`.
+    fn raw_integer(&self, expected: LogicalTypeId) -> Result<i128, FromValueError> {
+        use LogicalTypeId::*;
+        let found = self.logical_type_id();
+        let raw = match found {
+            Tinyint => unsafe { duckdb_get_int8(self.ptr) as i128 },
+            Smallint => unsafe { duckdb_get_int16(self.ptr) as i128 },
+            Integer => unsafe { duckdb_get_int32(self.ptr) as i128 },
+            Bigint => unsafe { duckdb_get_int64(self.ptr) as i128 },
+            UTinyint => unsafe { duckdb_get_uint8(self.ptr) as i128 },
+            USmallint => unsafe { duckdb_get_uint16(self.ptr) as i128 },
+            UInteger => unsafe { duckdb_get_uint32(self.ptr) as i128 },
+            UBigint => unsafe { duckdb_get_uint64(self.ptr) as i128 },
+            _ => return Err(FromValueError::InvalidType { expected, found }),
+        };
+        Ok(raw)
+    }
+}
+
+/// Error returned when a [`Value`] can't be converted to the requested Rust type,
+/// either because its `logical_type_id()` doesn't match or because the value
+/// doesn't fit in the target type's range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromValueError {
+    /// The value's logical type wasn't the one the conversion expected.
+    InvalidType {
+        expected: LogicalTypeId,
+        found: LogicalTypeId,
+    },
+    /// The value's logical type matched, but its magnitude doesn't fit in the
+    /// target Rust type. Carries the full-width value that was read.
+    OutOfRange(i128),
+}
+
+impl fmt::Display for FromValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromValueError::InvalidType { expected, found } => {
+                write!(f, "invalid type: expected {expected:?}, found {found:?}")
+            }
+            FromValueError::OutOfRange(raw) => write!(f, "value out of range: {raw}"),
+        }
+    }
+}
+
+impl std::error::Error for FromValueError {}
+
+/// Fallible, range-checked extraction of a Rust value from a [`Value`].
+///
+/// This mirrors DuckDB's own `FromSql`, but operates on the FFI-backed
+/// [`Value`] wrapper: implementations check `logical_type_id()` against the
+/// expected type before reading, rather than calling the FFI getter blindly.
+pub trait FromDuckValue: Sized {
+    fn from_duck_value(value: &Value) -> Result<Self, FromValueError>;
+}
+
+macro_rules! impl_from_duck_value_int {
+    ($($rust_type:ty => $expected:ident),* $(,)?) => {
+        $(
+            impl FromDuckValue for $rust_type {
+                fn from_duck_value(value: &Value) -> Result<Self, FromValueError> {
+                    let raw = value.raw_integer(LogicalTypeId::$expected)?;
+                    <$rust_type>::try_from(raw).map_err(|_| FromValueError::OutOfRange(raw))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_duck_value_int!(
+    i8 => Tinyint,
+    u8 => UTinyint,
+    i16 => Smallint,
+    u16 => USmallint,
+    i32 => Integer,
+    u32 => UInteger,
+    i64 => Bigint,
+    u64 => UBigint,
+);
+
+impl FromDuckValue for bool {
+    fn from_duck_value(value: &Value) -> Result<Self, FromValueError> {
+        value.require_type(LogicalTypeId::Boolean)?;
+        Ok(unsafe { duckdb_get_bool(value.ptr) })
+    }
+}
+
+impl FromDuckValue for f32 {
+    fn from_duck_value(value: &Value) -> Result<Self, FromValueError> {
+        value.require_type(LogicalTypeId::Float)?;
+        Ok(unsafe { duckdb_get_float(value.ptr) })
+    }
+}
+
+impl FromDuckValue for f64 {
+    fn from_duck_value(value: &Value) -> Result<Self, FromValueError> {
+        value.require_type(LogicalTypeId::Double)?;
+        Ok(unsafe { duckdb_get_double(value.ptr) })
+    }
+}
+
+impl FromDuckValue for String {
+    fn from_duck_value(value: &Value) -> Result<Self, FromValueError> {
+        value.require_type(LogicalTypeId::Varchar)?;
+        Ok(value.to_string())
+    }
 }
 
 impl fmt::Display for Value {
@@ -92,6 +399,181 @@ impl fmt::Display for Value {
     }
 }
 
+#[cfg(feature = "chrono")]
+use crate::ffi::{duckdb_get_date, duckdb_get_time, duckdb_get_timestamp};
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Days between `0000-12-31` (chrono's proleptic Gregorian calendar epoch) and
+/// `1970-01-01` (DuckDB's DATE epoch), so `duckdb_date.days` can be rebased
+/// onto `NaiveDate::from_num_days_from_ce_opt`.
+#[cfg(feature = "chrono")]
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+#[cfg(feature = "chrono")]
+impl Value {
+    /// Converts a DATE value to a [`NaiveDate`]. DuckDB stores DATE as days
+    /// since the Unix epoch (1970-01-01).
+    pub fn to_naive_date(&self) -> Result<NaiveDate, FromValueError> {
+        self.require_type(LogicalTypeId::Date)?;
+        let date = unsafe { duckdb_get_date(self.ptr) };
+        let days = date
+            .days
+            .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+            .ok_or(FromValueError::OutOfRange(date.days as i128))?;
+        NaiveDate::from_num_days_from_ce_opt(days).ok_or(FromValueError::OutOfRange(date.days as i128))
+    }
+
+    /// Converts a TIME value to a [`NaiveTime`]. DuckDB stores TIME as
+    /// microseconds since midnight.
+    pub fn to_naive_time(&self) -> Result<NaiveTime, FromValueError> {
+        self.require_type(LogicalTypeId::Time)?;
+        let time = unsafe { duckdb_get_time(self.ptr) };
+        let secs = (time.micros / 1_000_000) as u32;
+        let nanos = ((time.micros % 1_000_000) * 1_000) as u32;
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+            .ok_or(FromValueError::OutOfRange(time.micros as i128))
+    }
+
+    /// Converts a TIMESTAMP value to a [`NaiveDateTime`]. DuckDB stores
+    /// TIMESTAMP as microseconds since the Unix epoch.
+    pub fn to_naive_datetime(&self) -> Result<NaiveDateTime, FromValueError> {
+        self.require_type(LogicalTypeId::Timestamp)?;
+        let ts = unsafe { duckdb_get_timestamp(self.ptr) };
+        let secs = ts.micros.div_euclid(1_000_000);
+        let micros = ts.micros.rem_euclid(1_000_000);
+        chrono::DateTime::from_timestamp(secs, (micros * 1_000) as u32)
+            .map(|dt| dt.naive_utc())
+            .ok_or(FromValueError::OutOfRange(ts.micros as i128))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.is_null() {
+            return serializer.serialize_none();
+        }
+        use LogicalTypeId::*;
+        match self.logical_type_id() {
+            Boolean => serializer.serialize_bool(self.to_bool()),
+            Tinyint => serializer.serialize_i8(self.to_int8()),
+            Smallint => serializer.serialize_i16(self.to_int16()),
+            Integer => serializer.serialize_i32(self.to_int32()),
+            Bigint => serializer.serialize_i64(self.to_int64()),
+            UTinyint => serializer.serialize_u8(self.to_uint8()),
+            USmallint => serializer.serialize_u16(self.to_uint16()),
+            UInteger => serializer.serialize_u32(self.to_uint32()),
+            UBigint => serializer.serialize_u64(self.to_uint64()),
+            Float => serializer.serialize_f32(self.to_float()),
+            Double => serializer.serialize_f64(self.to_double()),
+            Varchar => serializer.serialize_str(&self.to_string()),
+            List => serializer.collect_seq(self.to_vec()),
+            Struct => serializer.collect_map(self.to_struct()),
+            // Every other logical type (DECIMAL, temporal types, BLOB, …) falls
+            // back to the same lossy varchar representation `Display` uses.
+            _ => serializer.serialize_str(&self.to_string()),
+        }
+    }
+}
+
+/// Builds a [`Value`] out of whatever self-describing shape `serde` hands us.
+/// Since a bare `deserialize_any` carries no DuckDB logical type, sequences
+/// are rebuilt as LIST values typed after their first element, and maps are
+/// rebuilt as STRUCT values keyed by their (string) field names.
+#[cfg(feature = "serde")]
+struct ValueVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a value representable as a DuckDB Value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::null())
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::null())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element::<Value>()? {
+            items.push(item);
+        }
+        let child_type = items.first().map(|v| v.logical_type_id()).unwrap_or(LogicalTypeId::Varchar);
+        Ok(Value::list(child_type, items))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut fields = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            fields.push((key, value));
+        }
+        Ok(Value::from_struct(fields))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Value {
+    /// Converts a UUID value to a [`uuid::Uuid`]. DuckDB's physical storage for
+    /// UUID is a hugeint with the sign bit of the upper 64 bits flipped, so
+    /// unsigned lexicographic order matches UUID's natural ordering; flip it
+    /// back to recover the UUID's big-endian bytes.
+    pub fn to_uuid(&self) -> Result<uuid::Uuid, FromValueError> {
+        self.require_type(LogicalTypeId::Uuid)?;
+        let raw = unsafe { duckdb_get_hugeint(self.ptr) };
+        let upper = (raw.upper as u64) ^ (1u64 << 63);
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&upper.to_be_bytes());
+        bytes[8..].copy_from_slice(&raw.lower.to_be_bytes());
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +614,79 @@ mod tests {
         assert_eq!(list.iter().map(|v| v.to_int64()).collect::<Vec<i64>>(), list_items);
     }
 
+    #[test]
+    fn test_value_to_struct() {
+        use crate::ffi::{
+            duckdb_create_logical_type, duckdb_create_struct_type, duckdb_create_struct_value, duckdb_create_varchar,
+            duckdb_destroy_logical_type, DUCKDB_TYPE_DUCKDB_TYPE_BIGINT, DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR,
+        };
+
+        let val = unsafe {
+            let mut member_types = [
+                duckdb_create_logical_type(DUCKDB_TYPE_DUCKDB_TYPE_BIGINT),
+                duckdb_create_logical_type(DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR),
+            ];
+            let names = [CString::new("id").unwrap(), CString::new("name").unwrap()];
+            let name_ptrs: Vec<_> = names.iter().map(|n| n.as_ptr()).collect();
+            let mut member_values = [
+                duckdb_create_int64(42),
+                duckdb_create_varchar(CString::new("alice").unwrap().as_ptr()),
+            ];
+            let mut struct_type = duckdb_create_struct_type(member_types.as_mut_ptr(), name_ptrs.as_ptr().cast_mut(), 2);
+            let duckdb_val = duckdb_create_struct_value(struct_type, member_values.as_mut_ptr());
+
+            for t in &mut member_types {
+                duckdb_destroy_logical_type(t);
+            }
+            duckdb_destroy_logical_type(&mut struct_type);
+            for v in &mut member_values {
+                duckdb_destroy_value(v);
+            }
+
+            Value::from(duckdb_val)
+        };
+
+        let fields = val.to_struct();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].0, "id");
+        assert_eq!(fields[0].1.to_int64(), 42);
+        assert_eq!(fields[1].0, "name");
+        assert_eq!(fields[1].1.to_string(), "alice");
+    }
+
+    #[test]
+    fn test_value_to_map() {
+        use crate::ffi::{
+            duckdb_create_logical_type, duckdb_create_map_type, duckdb_create_map_value, duckdb_destroy_logical_type,
+            DUCKDB_TYPE_DUCKDB_TYPE_BIGINT,
+        };
+
+        let val = unsafe {
+            let mut key_type = duckdb_create_logical_type(DUCKDB_TYPE_DUCKDB_TYPE_BIGINT);
+            let mut value_type = duckdb_create_logical_type(DUCKDB_TYPE_DUCKDB_TYPE_BIGINT);
+            let mut map_type = duckdb_create_map_type(key_type, value_type);
+            let mut keys = [duckdb_create_int64(1), duckdb_create_int64(2)];
+            let mut values = [duckdb_create_int64(10), duckdb_create_int64(20)];
+            let duckdb_val = duckdb_create_map_value(map_type, keys.as_mut_ptr(), values.as_mut_ptr(), 2);
+
+            duckdb_destroy_logical_type(&mut key_type);
+            duckdb_destroy_logical_type(&mut value_type);
+            duckdb_destroy_logical_type(&mut map_type);
+            for v in keys.iter_mut().chain(values.iter_mut()) {
+                duckdb_destroy_value(v);
+            }
+
+            Value::from(duckdb_val)
+        };
+
+        let entries = val.to_map();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0.to_int64(), 1);
+        assert_eq!(entries[0].1.to_int64(), 10);
+        assert_eq!(entries[1].0.to_int64(), 2);
+        assert_eq!(entries[1].1.to_int64(), 20);
+    }
+
     #[test]
     fn test_value_primitive_getters() {
         use crate::ffi::{
@@ -196,4 +751,249 @@ mod tests {
             assert!(null_val.is_null());
         }
     }
+
+    #[test]
+    fn test_value_get_checked() {
+        use crate::ffi::{duckdb_create_int32, duckdb_create_varchar};
+
+        unsafe {
+            let i32_val = Value::from(duckdb_create_int32(-123));
+            assert_eq!(i32_val.get::<i32>().unwrap(), -123);
+            assert!(matches!(
+                i32_val.get::<String>(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Varchar,
+                    found: LogicalTypeId::Integer,
+                })
+            ));
+
+            let str_val = Value::from(duckdb_create_varchar(CString::new("hi").unwrap().as_ptr()));
+            assert_eq!(str_val.get::<String>().unwrap(), "hi");
+
+            // A non-i64 integer getter must report its own logical type, not `Bigint`,
+            // when the underlying value isn't an integer at all.
+            assert!(matches!(
+                str_val.get::<i8>(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Tinyint,
+                    found: LogicalTypeId::Varchar,
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_value_constructors() {
+        let i32_val = Value::from(-200000i32);
+        assert_eq!(i32_val.to_int32(), -200000);
+
+        let str_val = Value::from("some value");
+        assert_eq!(str_val.to_string(), "some value");
+
+        let null_val = Value::null();
+        assert!(null_val.is_null());
+
+        let list_val = Value::list(
+            LogicalTypeId::Bigint,
+            vec![Value::from(1i64), Value::from(-200i64), Value::from(2381292i64)],
+        );
+        let list = list_val.to_vec();
+        assert_eq!(list.iter().map(|v| v.to_int64()).collect::<Vec<i64>>(), vec![
+            1, -200, 2381292
+        ]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_value_temporal_accessors() {
+        use crate::ffi::{
+            duckdb_create_date, duckdb_create_time, duckdb_create_timestamp, duckdb_date, duckdb_time, duckdb_timestamp,
+        };
+        use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+        unsafe {
+            let date_val = Value::from(duckdb_create_date(duckdb_date { days: 0 }));
+            assert_eq!(date_val.to_naive_date().unwrap(), NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+
+            let time_val = Value::from(duckdb_create_time(duckdb_time { micros: 3_661_000_000 }));
+            assert_eq!(time_val.to_naive_time().unwrap(), NaiveTime::from_hms_opt(1, 1, 1).unwrap());
+
+            let ts_val = Value::from(duckdb_create_timestamp(duckdb_timestamp { micros: 3_661_000_000 }));
+            assert_eq!(
+                ts_val.to_naive_datetime().unwrap(),
+                NaiveDateTime::new(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(), NaiveTime::from_hms_opt(1, 1, 1).unwrap())
+            );
+
+            let int_val = Value::from(1i32);
+            assert!(matches!(
+                int_val.to_naive_date(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Date,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_serialize() {
+        let int_val = Value::from(42i32);
+        assert_eq!(serde_json::to_string(&int_val).unwrap(), "42");
+
+        let str_val = Value::from("hello");
+        assert_eq!(serde_json::to_string(&str_val).unwrap(), "\"hello\"");
+
+        let null_val = Value::null();
+        assert_eq!(serde_json::to_string(&null_val).unwrap(), "null");
+
+        let list_val = Value::list(LogicalTypeId::Bigint, vec![Value::from(1i64), Value::from(2i64)]);
+        assert_eq!(serde_json::to_string(&list_val).unwrap(), "[1,2]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_deserialize_round_trip() {
+        let int_val: Value = serde_json::from_str("42").unwrap();
+        assert_eq!(int_val.get::<i64>().unwrap(), 42);
+
+        let str_val: Value = serde_json::from_str("\"hello\"").unwrap();
+        assert_eq!(str_val.get::<String>().unwrap(), "hello");
+
+        let null_val: Value = serde_json::from_str("null").unwrap();
+        assert!(null_val.is_null());
+
+        let list_val: Value = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(
+            list_val.to_vec().iter().map(|v| v.to_int64()).collect::<Vec<i64>>(),
+            vec![1, 2, 3]
+        );
+
+        // STRUCT round-trips through a JSON object, the case `ValueVisitor::visit_map` exists for.
+        let struct_val: Value = serde_json::from_str("{\"id\":1,\"name\":\"alice\"}").unwrap();
+        let fields = struct_val.to_struct();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].0, "id");
+        assert_eq!(fields[0].1.to_int64(), 1);
+        assert_eq!(fields[1].0, "name");
+        assert_eq!(fields[1].1.to_string(), "alice");
+
+        // A nested array/object's inner LIST/STRUCT value must keep its own
+        // element/member type: `Value::list`/`Value::from_struct` derive it from
+        // the child's own logical type, not `visit_seq`/`visit_map`'s bare tag.
+        let nested_list_val: Value = serde_json::from_str("[[1,2],[3,4]]").unwrap();
+        let outer = nested_list_val.to_vec();
+        assert_eq!(outer.len(), 2);
+        assert_eq!(
+            outer[0].to_vec().iter().map(|v| v.to_int64()).collect::<Vec<i64>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            outer[1].to_vec().iter().map(|v| v.to_int64()).collect::<Vec<i64>>(),
+            vec![3, 4]
+        );
+
+        let nested_struct_val: Value = serde_json::from_str("{\"point\":{\"x\":1,\"y\":2}}").unwrap();
+        let fields = nested_struct_val.to_struct();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].0, "point");
+        let inner = fields[0].1.to_struct();
+        assert_eq!(inner[0].0, "x");
+        assert_eq!(inner[0].1.to_int64(), 1);
+        assert_eq!(inner[1].0, "y");
+        assert_eq!(inner[1].1.to_int64(), 2);
+    }
+
+    #[test]
+    fn test_value_hugeint_and_decimal() {
+        use crate::ffi::{
+            duckdb_create_decimal, duckdb_create_hugeint, duckdb_create_uhugeint, duckdb_decimal, duckdb_hugeint,
+            duckdb_uhugeint,
+        };
+
+        unsafe {
+            let hugeint_val = Value::from(duckdb_create_hugeint(duckdb_hugeint { lower: 1, upper: -1 }));
+            assert_eq!(hugeint_val.to_i128().unwrap(), ((-1i128) << 64) | 1);
+
+            let int_val = Value::from(1i32);
+            assert!(matches!(
+                int_val.to_i128(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Hugeint,
+                    ..
+                })
+            ));
+
+            let uhugeint_val = Value::from(duckdb_create_uhugeint(duckdb_uhugeint { lower: 1, upper: 1 }));
+            assert_eq!(uhugeint_val.to_u128().unwrap(), (1u128 << 64) | 1);
+
+            assert!(matches!(
+                int_val.to_u128(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Uhugeint,
+                    ..
+                })
+            ));
+
+            let decimal_val = Value::from(duckdb_create_decimal(duckdb_decimal {
+                width: 9,
+                scale: 2,
+                value: duckdb_hugeint { lower: 12345, upper: 0 },
+            }));
+            assert_eq!(decimal_val.to_decimal().unwrap(), (12345, 2));
+
+            assert!(matches!(
+                int_val.to_decimal(),
+                Err(FromValueError::InvalidType {
+                    expected: LogicalTypeId::Decimal,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_value_to_blob() {
+        use crate::ffi::duckdb_create_blob;
+
+        let bytes = [1u8, 2, 3, 4];
+        let blob_val = unsafe { Value::from(duckdb_create_blob(bytes.as_ptr(), bytes.len() as u64)) };
+        assert_eq!(blob_val.to_blob(), bytes);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_value_to_uuid() {
+        use crate::ffi::{duckdb_create_blob, duckdb_create_uuid, duckdb_uhugeint};
+
+        let uuid = uuid::Uuid::from_bytes([
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00,
+        ]);
+        let bytes = *uuid.as_bytes();
+        let upper = u64::from_be_bytes(bytes[..8].try_into().unwrap());
+        let lower = u64::from_be_bytes(bytes[8..].try_into().unwrap());
+        let uuid_val = unsafe { Value::from(duckdb_create_uuid(duckdb_uhugeint { lower, upper })) };
+        assert_eq!(uuid_val.to_uuid().unwrap(), uuid);
+
+        let blob_val = unsafe { Value::from(duckdb_create_blob(uuid.as_bytes().as_ptr(), 16)) };
+        // `duckdb_create_blob` gives us BLOB, not UUID, logical type, so the
+        // type check rejects it until the value actually carries UUID type.
+        assert!(matches!(
+            blob_val.to_uuid(),
+            Err(FromValueError::InvalidType {
+                expected: LogicalTypeId::Uuid,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_value_get_out_of_range() {
+        use crate::ffi::duckdb_create_int64;
+
+        unsafe {
+            let i64_val = Value::from(duckdb_create_int64(i64::MAX));
+            assert!(matches!(i64_val.get::<i8>(), Err(FromValueError::OutOfRange(_))));
+        }
+    }
 }